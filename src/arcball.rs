@@ -0,0 +1,91 @@
+//! An interactive arcball camera controller.
+//!
+//! Implements Shoemake's arcball: screen-space drags are projected onto a
+//! virtual unit sphere and turned into a rotation that is accumulated into
+//! the controller's orientation and fed to `Camera::set_rotation`.
+
+use cgmath::prelude::*;
+use cgmath::{dot, BaseFloat, Quaternion, Rad, Vector2, Vector3};
+
+use camera::Camera;
+
+/// Rotates, pans, and zooms a `Camera` from mouse/touch pointer input.
+pub struct ArcballController<T = f32> {
+    /// The accumulated orientation applied to the camera.
+    pub orientation: Quaternion<T>,
+    /// Size of the screen/viewport in pixels, used to normalize drags.
+    pub screen_size: [T; 2],
+    /// Cached `1 / screen_size`, kept up to date by `resize`.
+    pub inv_screen: [T; 2],
+    /// World units per screen pixel applied to pan deltas.
+    pub motion_speed: T,
+    /// World units per `zoom` unit applied along the forward axis.
+    pub zoom_speed: T,
+}
+
+impl<T: BaseFloat> ArcballController<T> {
+    /// Constructs a new controller for a viewport of the given size.
+    pub fn new(screen_size: [T; 2]) -> ArcballController<T> {
+        let _1: T = One::one();
+        ArcballController {
+            orientation: Quaternion::one(),
+            screen_size,
+            inv_screen: [_1 / screen_size[0], _1 / screen_size[1]],
+            motion_speed: _1,
+            zoom_speed: _1,
+        }
+    }
+
+    /// Updates the stored screen size (and cached inverse) after a resize.
+    pub fn resize(&mut self, screen_size: [T; 2]) {
+        let _1: T = One::one();
+        self.screen_size = screen_size;
+        self.inv_screen = [_1 / screen_size[0], _1 / screen_size[1]];
+    }
+
+    /// Projects a screen-space point onto the virtual arcball sphere.
+    fn project(&self, screen: Vector2<T>) -> Vector3<T> {
+        let _1: T = One::one();
+        let _2: T = _1 + _1;
+        let x = _2 * screen.x * self.inv_screen[0] - _1;
+        let y = _1 - _2 * screen.y * self.inv_screen[1];
+        let d2 = x * x + y * y;
+        if d2 <= _1 {
+            Vector3::new(x, y, (_1 - d2).sqrt())
+        } else {
+            let d = d2.sqrt();
+            Vector3::new(x / d, y / d, Zero::zero())
+        }
+    }
+
+    /// Rotates `camera` by the drag from `prev_screen` to `cur_screen` and
+    /// applies the result through `Camera::set_rotation`.
+    ///
+    /// Degenerate drags, where the two sphere points are nearly identical
+    /// and the rotation axis would be zero-length, are ignored to avoid
+    /// producing a NaN quaternion.
+    pub fn rotate(&mut self, camera: &mut Camera<T>, prev_screen: Vector2<T>, cur_screen: Vector2<T>) {
+        let v0 = self.project(prev_screen);
+        let v1 = self.project(cur_screen);
+        let axis = v0.cross(v1);
+        if axis.magnitude2() < T::epsilon() {
+            return;
+        }
+        let _1: T = One::one();
+        let cos_angle = dot(v0, v1).min(_1).max(-_1);
+        let delta = Quaternion::from_axis_angle(axis.normalize(), Rad(cos_angle.acos()));
+        self.orientation = (delta * self.orientation).normalize();
+        camera.set_rotation(self.orientation);
+    }
+
+    /// Pans `camera` along its right/up vectors by `delta` screen pixels.
+    pub fn pan(&mut self, camera: &mut Camera<T>, delta: Vector2<T>) {
+        camera.position += camera.right * (delta.x * self.motion_speed);
+        camera.position += camera.up * (delta.y * self.motion_speed);
+    }
+
+    /// Zooms `camera` by moving it along its forward axis.
+    pub fn zoom(&mut self, camera: &mut Camera<T>, amount: T) {
+        camera.position += camera.forward * (amount * self.zoom_speed);
+    }
+}
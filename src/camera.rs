@@ -1,11 +1,16 @@
 //! A 3D camera.
 
+use cgmath::num_traits::NumCast;
 use cgmath::prelude::*;
 use cgmath::Rad;
 use cgmath::{dot, BaseFloat, Matrix4, Quaternion, Vector3};
 use std::ops::Mul;
 use std::ops::Sub;
 
+fn cast<T: NumCast>(x: f64) -> T {
+    NumCast::from(x).unwrap()
+}
+
 /// Computes a model view projection matrix.
 pub fn model_view_projection<T: BaseFloat + Copy>(
     model: Matrix4<T>,
@@ -102,24 +107,63 @@ impl<T: BaseFloat + Copy> Camera<T> {
     }
 }
 
-impl<T: BaseFloat> CameraPerspective<T>
-where
-    f64: Into<T>,
-{
+impl<T: BaseFloat> CameraPerspective<T> {
     /// Computes a projection matrix for the camera perspective.
+    pub fn projection(&self) -> Matrix4<T> {
+        let _1: T = One::one();
+        let pi: T = cast(3.14116);
+        let _360: T = cast(360.0);
+        let f = _1 / (self.fov * (pi / _360)).tan();
+        let top = self.near_clip / f;
+        let right = top * self.aspect_ratio;
+        CameraFrustum {
+            left: -right,
+            right,
+            bottom: -top,
+            top,
+            near_clip: self.near_clip,
+            far_clip: self.far_clip,
+        }.projection()
+    }
+}
+
+/// Models an arbitrary, possibly asymmetric, view frustum for off-axis
+/// projections.
+pub struct CameraFrustum<T = f32> {
+    /// The left clip plane distance.
+    pub left: T,
+    /// The right clip plane distance.
+    pub right: T,
+    /// The bottom clip plane distance.
+    pub bottom: T,
+    /// The top clip plane distance.
+    pub top: T,
+    /// The near clip distance.
+    pub near_clip: T,
+    /// The far clip distance.
+    pub far_clip: T,
+}
+
+impl<T: BaseFloat> CameraFrustum<T> {
+    /// Computes a projection matrix for this (possibly asymmetric) frustum.
+    ///
+    /// Panics if `near_clip` is not positive, if `far_clip` is not greater
+    /// than `near_clip`, if `right` is not greater than `left`, or if `top`
+    /// is not greater than `bottom`.
     pub fn projection(&self) -> Matrix4<T> {
         let _0: T = Zero::zero();
         let _1: T = One::one();
         let _2: T = _1 + _1;
-        let pi: T = 3.14116.into();
-        let _360: T = 360.0f64.into();
-        let f = _1 / (self.fov * (pi / _360)).tan();
-        let (far, near) = (self.far_clip, self.near_clip);
+        let (l, r, b, t, n, f) = (self.left, self.right, self.bottom, self.top, self.near_clip, self.far_clip);
+        assert!(n > _0, "near_clip must be positive");
+        assert!(f > n, "far_clip must be greater than near_clip");
+        assert!(r > l, "right must be greater than left");
+        assert!(t > b, "top must be greater than bottom");
         [
-            [f / self.aspect_ratio, _0, _0, _0],
-            [_0, f, _0, _0],
-            [_0, _0, (far + near) / (near - far), -_1],
-            [_0, _0, (_2 * far * near) / (near - far), _0],
+            [_2 * n / (r - l), _0, _0, _0],
+            [_0, _2 * n / (t - b), _0, _0],
+            [(r + l) / (r - l), (t + b) / (t - b), -(f + n) / (f - n), -_1],
+            [_0, _0, -(_2 * f * n) / (f - n), _0],
         ].into()
     }
 }
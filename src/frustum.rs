@@ -0,0 +1,84 @@
+//! Frustum extraction and culling.
+
+use cgmath::prelude::*;
+use cgmath::{dot, BaseFloat, Matrix4, Vector3, Vector4};
+
+/// A plane in normal-distance form: `dot(normal, p) + d = 0` for any point
+/// `p` on the plane, with `normal` pointing into the half-space considered
+/// "inside" the frustum.
+#[derive(Copy, Clone)]
+pub struct Plane<T = f32> {
+    /// The plane's unit normal.
+    pub normal: Vector3<T>,
+    /// The signed distance term.
+    pub d: T,
+}
+
+impl<T: BaseFloat> Plane<T> {
+    fn normalized(self) -> Plane<T> {
+        let len = self.normal.magnitude();
+        Plane {
+            normal: self.normal / len,
+            d: self.d / len,
+        }
+    }
+
+    /// The signed distance from `point` to the plane.
+    pub fn distance(&self, point: Vector3<T>) -> T {
+        dot(self.normal, point) + self.d
+    }
+}
+
+/// A view frustum, extracted from a combined view-projection (or
+/// model-view-projection) matrix, usable for view-frustum culling.
+pub struct Frustum<T = f32> {
+    /// The six clip planes, in left/right/bottom/top/near/far order.
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T: BaseFloat> Frustum<T> {
+    /// Extracts the six clip planes from a combined view-projection matrix
+    /// `m`, using the Gribb-Hartmann method.
+    pub fn from_matrix(m: Matrix4<T>) -> Frustum<T> {
+        let row = |i: usize| Vector4::new(m[0][i], m[1][i], m[2][i], m[3][i]);
+        let (r0, r1, r2, r3) = (row(0), row(1), row(2), row(3));
+        let plane = |v: Vector4<T>| {
+            Plane {
+                normal: Vector3::new(v.x, v.y, v.z),
+                d: v.w,
+            }.normalized()
+        };
+        Frustum {
+            planes: [
+                plane(r3 + r0), // left
+                plane(r3 - r0), // right
+                plane(r3 + r1), // bottom
+                plane(r3 - r1), // top
+                plane(r3 + r2), // near
+                plane(r3 - r2), // far
+            ],
+        }
+    }
+
+    /// Returns `true` if the sphere is at least partially inside the
+    /// frustum.
+    pub fn contains_sphere(&self, center: Vector3<T>, radius: T) -> bool {
+        self.planes
+            .iter()
+            .all(|p| p.distance(center) >= -radius)
+    }
+
+    /// Returns `true` if the AABB `(min, max)` is at least partially inside
+    /// the frustum, using the positive-vertex test.
+    pub fn contains_aabb(&self, min: Vector3<T>, max: Vector3<T>) -> bool {
+        let _0: T = Zero::zero();
+        self.planes.iter().all(|p| {
+            let positive = Vector3::new(
+                if p.normal.x >= _0 { max.x } else { min.x },
+                if p.normal.y >= _0 { max.y } else { min.y },
+                if p.normal.z >= _0 { max.z } else { min.z },
+            );
+            p.distance(positive) >= _0
+        })
+    }
+}
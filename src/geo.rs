@@ -0,0 +1,178 @@
+//! Geographic (Web Mercator) camera mode for map-style navigation.
+
+use cgmath::num_traits::NumCast;
+use cgmath::prelude::*;
+use cgmath::{BaseFloat, Vector3};
+
+use camera::{Camera, CameraPerspective};
+
+/// The Web Mercator projection's pole limit, in degrees. Latitudes are
+/// clamped to this range so `merc_y` stays finite.
+const MAX_LATITUDE: f64 = 85.05112878;
+
+fn cast<T: NumCast>(x: f64) -> T {
+    NumCast::from(x).unwrap()
+}
+
+/// A camera driven by geographic coordinates, for slippy-map / globe style
+/// navigation. Converts to the world-space `Camera` via `position_from_geo`.
+pub struct GeoCamera<T = f32> {
+    /// Longitude of the view center, in degrees.
+    pub lng: T,
+    /// Latitude of the view center, in degrees (clamped to the Mercator
+    /// pole limit).
+    pub lat: T,
+    /// Fractional zoom level; each whole step doubles the map's size in
+    /// world units.
+    pub zoom: T,
+    /// Rotation about the vertical axis, in radians.
+    pub bearing: T,
+    /// Tilt of the view toward the horizon, in radians (`0` looks straight
+    /// down).
+    pub pitch: T,
+    /// The size, in world units, of one map tile at zoom `0`.
+    pub tile_size: T,
+    /// The camera this `GeoCamera` drives.
+    pub camera: Camera<T>,
+    /// The perspective used to derive eye height from the field of view.
+    pub perspective: CameraPerspective<T>,
+}
+
+impl<T: BaseFloat> GeoCamera<T> {
+    /// Constructs a new `GeoCamera` centered at `(0, 0)` at zoom `0`.
+    pub fn new(perspective: CameraPerspective<T>) -> GeoCamera<T> {
+        let _0: T = Zero::zero();
+        let mut geo = GeoCamera {
+            lng: _0,
+            lat: _0,
+            zoom: _0,
+            bearing: _0,
+            pitch: _0,
+            tile_size: cast(256.0),
+            camera: Camera::new([_0, _0, _0].into()),
+            perspective,
+        };
+        geo.position_from_geo();
+        geo
+    }
+
+    /// Sets the geographic center of the view, clamping latitude to the
+    /// Mercator pole limit (~85.05 degrees).
+    pub fn set_center(&mut self, lng: T, lat: T) {
+        self.lng = lng;
+        self.lat = lat;
+        self.position_from_geo();
+    }
+
+    /// Sets the zoom level. Zoom is fractional: each whole step doubles the
+    /// map's size in world units.
+    pub fn set_zoom(&mut self, zoom: T) {
+        self.zoom = zoom;
+        self.position_from_geo();
+    }
+
+    /// Sets the bearing (rotation about the vertical axis), in radians.
+    pub fn set_bearing(&mut self, bearing: T) {
+        self.bearing = bearing;
+        self.position_from_geo();
+    }
+
+    /// Sets the pitch (tilt toward the horizon), in radians.
+    pub fn set_pitch(&mut self, pitch: T) {
+        self.pitch = pitch;
+        self.position_from_geo();
+    }
+
+    /// Projects `(lng, lat)`, in degrees, to normalized Web Mercator
+    /// coordinates in `[0, 1]`.
+    fn project(lng: T, lat: T) -> (T, T) {
+        let _1: T = One::one();
+        let _2: T = _1 + _1;
+        let _4: T = _2 + _2;
+        let _180: T = cast(180.0);
+        let _360: T = cast(360.0);
+        let pi: T = cast(::std::f64::consts::PI);
+        let merc_x = (lng + _180) / _360;
+        let merc_y = _1 / _2 - (pi / _4 + lat * pi / _360).tan().ln() / (_2 * pi);
+        (merc_x, merc_y)
+    }
+
+    /// Recomputes `camera`'s position and orientation from the current
+    /// geographic state, so that `(lng, lat)` stays at the center of the
+    /// screen regardless of zoom or pitch.
+    ///
+    /// `Camera::set_yaw_pitch`'s pitch is measured from the horizon, while
+    /// `GeoCamera::pitch` is measured from straight down, so `pitch` is
+    /// offset by a quarter turn before being handed to it. `lat` is
+    /// clamped to the Mercator pole limit here, so the clamp holds even if
+    /// `lat` was set directly rather than through `set_center`.
+    pub fn position_from_geo(&mut self) {
+        let _1: T = One::one();
+        let _2: T = _1 + _1;
+        let _360: T = cast(360.0);
+        let pi: T = cast(::std::f64::consts::PI);
+        let half_pi = pi / _2;
+        let max_lat: T = cast(MAX_LATITUDE);
+        self.lat = self.lat.max(-max_lat).min(max_lat);
+
+        let (merc_x, merc_y) = Self::project(self.lng, self.lat);
+        let scale = self.tile_size * _2.powf(self.zoom);
+        let center = Vector3::new(merc_x * scale, Zero::zero(), merc_y * scale);
+
+        self.camera.set_yaw_pitch(self.bearing, self.pitch + half_pi);
+
+        let half_fov = self.perspective.fov * (pi / _360);
+        let eye_height = scale / (_2 * half_fov.tan());
+        let distance = eye_height / self.pitch.cos().max(T::epsilon());
+
+        self.camera.position = center + self.camera.forward * distance;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frustum::Frustum;
+    use render_camera::RenderCamera;
+
+    fn perspective() -> CameraPerspective<f32> {
+        CameraPerspective {
+            fov: 60.0,
+            near_clip: 0.1,
+            far_clip: 10_000.0,
+            aspect_ratio: 1.0,
+        }
+    }
+
+    #[test]
+    fn center_stays_in_view_at_default_pitch() {
+        let mut geo = GeoCamera::new(perspective());
+        geo.set_center(10.0, 20.0);
+        geo.set_zoom(2.0);
+
+        let frustum = Frustum::from_matrix(geo.model_view_projection());
+        let (merc_x, merc_y) = GeoCamera::project(geo.lng, geo.lat);
+        let scale = geo.tile_size * 2f32.powf(geo.zoom);
+        let center = Vector3::new(merc_x * scale, 0.0, merc_y * scale);
+
+        assert!(frustum.contains_sphere(center, 0.1), "geo center should be in view at pitch 0");
+
+        let above_camera = geo.camera.position + Vector3::new(0.0, 100.0, 0.0);
+        assert!(
+            !frustum.contains_sphere(above_camera, 0.1),
+            "a point above the camera should not be in view when looking straight down"
+        );
+    }
+
+    #[test]
+    fn latitude_is_clamped_even_when_set_directly() {
+        let mut geo = GeoCamera::new(perspective());
+        geo.lat = 90.0;
+        geo.position_from_geo();
+
+        assert!(geo.lat <= 85.06 && geo.lat >= 85.0);
+        assert!(geo.camera.position.x.is_finite());
+        assert!(geo.camera.position.y.is_finite());
+        assert!(geo.camera.position.z.is_finite());
+    }
+}
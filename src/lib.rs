@@ -7,8 +7,17 @@ extern crate cgmath;
 
 pub use camera::{
     Camera,
+    CameraFrustum,
     CameraPerspective,
     model_view_projection,
 };
+pub use arcball::ArcballController;
+pub use frustum::{Frustum, Plane};
+pub use geo::GeoCamera;
+pub use render_camera::{CameraView, RenderCamera};
 
+mod arcball;
 mod camera;
+mod frustum;
+mod geo;
+mod render_camera;
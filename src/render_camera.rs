@@ -0,0 +1,113 @@
+//! A `RenderCamera` trait abstraction with pluggable view/projection.
+
+use std::cell::Cell;
+
+use cgmath::prelude::*;
+use cgmath::{BaseFloat, Matrix4, Vector3};
+
+use camera::{Camera, CameraPerspective};
+use geo::GeoCamera;
+
+/// A camera capable of producing view and projection matrices.
+///
+/// Lets renderers stay generic over different camera kinds (`CameraView`,
+/// `GeoCamera`, ...) instead of depending on a concrete pairing.
+pub trait RenderCamera<T: BaseFloat> {
+    /// Computes the view matrix.
+    fn view(&self) -> Matrix4<T>;
+
+    /// Computes the projection matrix.
+    fn projection(&self) -> Matrix4<T>;
+
+    /// Computes the combined view-projection matrix.
+    fn model_view_projection(&self) -> Matrix4<T> {
+        self.projection() * self.view()
+    }
+
+    /// Computes the inverse of the view-projection matrix, for unprojecting
+    /// screen rays (e.g. mouse picking).
+    fn inverse_mvp(&self) -> Matrix4<T>;
+
+    /// Unprojects a point in normalized device coordinates (`x`, `y`, `z`
+    /// all in `[-1, 1]`) into world space.
+    ///
+    /// Guards against a zero `w` component after the inverse transform by
+    /// clamping it away from zero rather than dividing by it directly.
+    fn unproject(&self, screen_ndc: Vector3<T>) -> Vector3<T> {
+        let clip = self.inverse_mvp() * screen_ndc.extend(One::one());
+        let w = if clip.w.abs() < T::epsilon() {
+            if clip.w.is_sign_negative() { -T::epsilon() } else { T::epsilon() }
+        } else {
+            clip.w
+        };
+        clip.truncate() / w
+    }
+}
+
+/// Pairs a `Camera` with a `CameraPerspective` and caches the inverse of
+/// their combined view-projection matrix.
+///
+/// The cache is not observed automatically: after mutating `camera` or
+/// `perspective` directly, call `invalidate` before the next `inverse_mvp`
+/// or `unproject`.
+pub struct CameraView<T = f32> {
+    /// The underlying camera.
+    pub camera: Camera<T>,
+    /// The underlying perspective.
+    pub perspective: CameraPerspective<T>,
+    inverse_cache: Cell<Option<Matrix4<T>>>,
+}
+
+impl<T: BaseFloat> CameraView<T> {
+    /// Constructs a new `CameraView` from a camera and its perspective.
+    pub fn new(camera: Camera<T>, perspective: CameraPerspective<T>) -> CameraView<T> {
+        CameraView {
+            camera,
+            perspective,
+            inverse_cache: Cell::new(None),
+        }
+    }
+
+    /// Clears the cached inverse view-projection matrix, forcing the next
+    /// `inverse_mvp`/`unproject` call to recompute it.
+    pub fn invalidate(&mut self) {
+        self.inverse_cache.set(None);
+    }
+}
+
+impl<T: BaseFloat> RenderCamera<T> for CameraView<T> {
+    fn view(&self) -> Matrix4<T> {
+        self.camera.orthogonal()
+    }
+
+    fn projection(&self) -> Matrix4<T> {
+        self.perspective.projection()
+    }
+
+    fn inverse_mvp(&self) -> Matrix4<T> {
+        if let Some(m) = self.inverse_cache.get() {
+            return m;
+        }
+        let m = self.model_view_projection()
+            .invert()
+            .expect("camera view-projection matrix is not invertible");
+        self.inverse_cache.set(Some(m));
+        m
+    }
+}
+
+impl<T: BaseFloat> RenderCamera<T> for GeoCamera<T> {
+    fn view(&self) -> Matrix4<T> {
+        self.camera.orthogonal()
+    }
+
+    fn projection(&self) -> Matrix4<T> {
+        self.perspective.projection()
+    }
+
+    fn inverse_mvp(&self) -> Matrix4<T> {
+        self.model_view_projection()
+            .invert()
+            .expect("camera view-projection matrix is not invertible")
+    }
+}